@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const ENGLISH: &str = include_str!("words.txt");
+
+/// A set of word lists keyed by language, selectable via `--lang`. The
+/// embedded English list is always available; others are loaded at runtime.
+pub struct Dictionaries {
+    lists: HashMap<String, String>,
+}
+
+impl Dictionaries {
+    /// Builds the set with only the embedded English list available.
+    pub fn builtin() -> Self {
+        let mut lists = HashMap::new();
+        lists.insert("english".to_string(), ENGLISH.to_string());
+        Self { lists }
+    }
+
+    /// Loads `path` and registers its contents as the word list for `lang`,
+    /// overriding any existing list of that name.
+    pub fn load(&mut self, lang: &str, path: &Path) -> io::Result<()> {
+        let contents = fs::read_to_string(path)?;
+        self.lists.insert(lang.to_string(), contents);
+        Ok(())
+    }
+
+    /// Returns the word list for `lang`, falling back to the embedded
+    /// English list if `lang` has not been loaded.
+    pub fn words(&self, lang: &str) -> &str {
+        self.lists
+            .get(lang)
+            .map_or(ENGLISH, |contents| contents.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_falls_back_to_english() {
+        let dictionaries = Dictionaries::builtin();
+        assert_eq!(dictionaries.words("french"), ENGLISH);
+    }
+
+    #[test]
+    fn test_load_registers_new_language() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("unhang_test_dict.txt");
+        fs::write(&path, "chat\nchien\n").unwrap();
+
+        let mut dictionaries = Dictionaries::builtin();
+        dictionaries.load("french", &path).unwrap();
+
+        assert_eq!(dictionaries.words("french"), "chat\nchien\n");
+        fs::remove_file(&path).unwrap();
+    }
+}
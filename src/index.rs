@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use crate::BLANK_CHAR;
+
+pub trait HangCompare<T> {
+    fn compare(&self, rhs: T) -> bool;
+}
+
+impl HangCompare<&str> for &str {
+    fn compare(&self, rhs: &str) -> bool {
+        if self.chars().count() == rhs.chars().count() {
+            self.chars()
+                .zip(rhs.chars())
+                .all(|(a, b)| a == b || a == BLANK_CHAR || b == BLANK_CHAR)
+        } else {
+            false
+        }
+    }
+}
+
+impl HangCompare<&[Option<char>]> for &str {
+    fn compare(&self, rhs: &[Option<char>]) -> bool {
+        if self.chars().count() == rhs.len() {
+            self.chars()
+                .zip(rhs.iter())
+                .all(|(a, b)| Some(a) == *b || a == BLANK_CHAR || b.is_none())
+        } else {
+            false
+        }
+    }
+}
+
+struct CandidateWord {
+    word: String,
+    mask: u64,
+}
+
+/// The remaining candidate words, pruned in place each turn rather than
+/// rescanned from the full dictionary. Each word carries a bitmask over a
+/// dynamically discovered alphabet (not assumed to be `a..=z`); letters
+/// past the 64-bit cap have no assigned bit and fall back to a full scan.
+pub struct CandidateIndex {
+    entries: Vec<CandidateWord>,
+    alphabet: HashMap<char, u32>,
+}
+
+impl CandidateIndex {
+    pub fn new(words: Vec<String>) -> Self {
+        let mut alphabet = HashMap::new();
+        for word in &words {
+            for c in word.chars() {
+                if alphabet.len() >= 64 {
+                    break;
+                }
+                // Capped to 64 above, so this always fits in a u32.
+                #[allow(clippy::cast_possible_truncation)]
+                let next_bit = alphabet.len() as u32;
+                alphabet.entry(c).or_insert(next_bit);
+            }
+        }
+
+        let entries = words
+            .into_iter()
+            .map(|word| {
+                let mask = Self::mask_of(&word, &alphabet);
+                CandidateWord { word, mask }
+            })
+            .collect();
+        Self { entries, alphabet }
+    }
+
+    fn mask_of(word: &str, alphabet: &HashMap<char, u32>) -> u64 {
+        word.chars().fold(0, |mask, c| {
+            alphabet.get(&c).map_or(mask, |bit| mask | (1 << bit))
+        })
+    }
+
+    /// Drop every candidate containing `letter`.
+    pub fn reject_letter(&mut self, letter: char) {
+        match self.alphabet.get(&letter) {
+            Some(&bit) => {
+                let bit_mask = 1u64 << bit;
+                self.entries.retain(|entry| entry.mask & bit_mask == 0);
+            }
+            // Past the 64-bit cap: no assigned bit, fall back to a full scan.
+            None => self.entries.retain(|entry| !entry.word.contains(letter)),
+        }
+    }
+
+    /// Drop every candidate that no longer matches the revealed `pattern`.
+    pub fn reveal(&mut self, pattern: &[Option<char>]) {
+        self.entries
+            .retain(|entry| entry.word.as_str().compare(pattern));
+    }
+
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|entry| entry.word.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_str() {
+        assert!("t__t".compare("test"));
+        assert!(!"t__t".compare("max"));
+        assert!(!"t__t".compare("naur"));
+    }
+
+    #[test]
+    fn test_compare_slice() {
+        assert!("test".compare([Some('t'), None, None, Some('t')].as_slice()));
+        assert!(!"test".compare([Some('t'), None, Some('t')].as_slice()));
+        assert!(!"test".compare([Some('s'), None, None, Some('t')].as_slice()));
+    }
+
+    #[test]
+    fn test_reject_letter_prunes_in_place() {
+        let mut index = CandidateIndex::new(vec!["test".into(), "rest".into(), "cart".into()]);
+        index.reject_letter('e');
+        assert_eq!(index.words().collect::<Vec<_>>(), vec!["cart"]);
+    }
+
+    #[test]
+    fn test_reveal_prunes_by_pattern() {
+        // "t__t" matches "test" and "tart" but not "cart" (wrong first letter).
+        let mut index = CandidateIndex::new(vec!["test".into(), "tart".into(), "cart".into()]);
+        index.reveal(&[Some('t'), None, None, Some('t')]);
+        assert_eq!(index.len(), 2);
+    }
+
+    #[test]
+    fn test_reject_letter_handles_accented_alphabet() {
+        let mut index = CandidateIndex::new(vec!["chien".into(), "chat".into(), "écureuil".into()]);
+        index.reject_letter('é');
+        assert_eq!(index.len(), 2);
+    }
+
+    #[test]
+    fn test_reject_letter_falls_back_past_alphabet_cap() {
+        // Fill all 64 bits with Greek letters so 'z' never claims one and
+        // has to go through the full-scan fallback instead.
+        let filler: String = (0u32..64)
+            .map(|i| char::from_u32(0x391 + i).unwrap())
+            .collect();
+        let mut index = CandidateIndex::new(vec![filler.clone(), format!("{filler}z")]);
+        index.reject_letter('z');
+        assert_eq!(index.words().collect::<Vec<_>>(), vec![filler.as_str()]);
+    }
+
+    #[test]
+    fn test_compare_counts_unicode_chars_not_bytes() {
+        assert!("écureuil".compare("éc______"));
+    }
+}
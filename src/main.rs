@@ -1,105 +1,122 @@
 #![warn(clippy::pedantic, clippy::nursery)]
 
-use std::collections::HashMap;
-// use std::fs;
-use std::env;
 use std::io;
+use std::path::PathBuf;
 
-const BLANK_CHAR: char = '_';
-const WORDS: &str = include_str!("words.txt");
+use clap::{ArgGroup, CommandFactory, Parser};
 
-trait HangCompare<T> {
-    fn compare(&self, rhs: T) -> bool;
-}
+use dict::Dictionaries;
+use index::CandidateIndex;
+use output::Format;
+use score::Strategy;
 
-impl HangCompare<&str> for &str {
-    fn compare(&self, rhs: &str) -> bool {
-        if self.len() == rhs.len() {
-            self.chars()
-                .zip(rhs.chars())
-                .all(|(a, b)| a == b || a == BLANK_CHAR || b == BLANK_CHAR)
-        } else {
-            false
-        }
-    }
-}
+mod dict;
+mod index;
+mod output;
+mod score;
 
-impl HangCompare<&Vec<Option<char>>> for &str {
-    fn compare(&self, rhs: &Vec<Option<char>>) -> bool {
-        if self.len() == rhs.len() {
-            self.chars()
-                .zip(rhs.iter())
-                .all(|(a, b)| Some(a) == *b || a == BLANK_CHAR || b.is_none())
-        } else {
-            false
-        }
-    }
+const BLANK_CHAR: char = '_';
+
+/// An interactive assistant that suggests letters while solving a hangman puzzle.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+#[command(group(ArgGroup::new("length_source").required(true).args(["length", "pattern"])))]
+struct Cli {
+    /// Length of the word to solve
+    #[arg(short, long)]
+    length: Option<usize>,
+
+    /// A partially-revealed pattern to infer the length from, e.g. `t__t`
+    #[arg(short = 'P', long)]
+    pattern: Option<String>,
+
+    /// Path to a custom word list, registered under `--lang`
+    #[arg(long)]
+    dict: Option<PathBuf>,
+
+    /// Which language's word list to use
+    #[arg(long, default_value = "english")]
+    lang: String,
+
+    /// Letters already guessed and known to be wrong, e.g. "qxz"
+    #[arg(long, default_value = "")]
+    missed: String,
+
+    /// Number of suggested letters to print each turn
+    #[arg(long, default_value_t = 3)]
+    top: usize,
+
+    /// How to rank candidate letters
+    #[arg(long, value_enum, default_value = "frequency")]
+    strategy: Strategy,
+
+    /// Output format for each turn's state
+    #[arg(long, value_enum, default_value = "text")]
+    format: Format,
 }
 
 fn main() {
-    let mut map = HashMap::new();
-
-    for word in WORDS.lines() {
-        let mut letters = HashMap::new();
-
-        for c in word.chars() {
-            letters
-                .entry(c.to_ascii_lowercase())
-                .and_modify(|e| *e += 1)
-                .or_insert(1);
+    let cli = Cli::parse();
+
+    let mut dictionaries = Dictionaries::builtin();
+    if let Some(path) = &cli.dict {
+        if let Err(err) = dictionaries.load(&cli.lang, path) {
+            Cli::command()
+                .error(
+                    clap::error::ErrorKind::Io,
+                    format!("failed to read dictionary {}: {err}", path.display()),
+                )
+                .exit();
         }
-
-        map.insert(word.to_lowercase(), letters);
     }
 
-    let word_length = env::args()
-        .nth(1)
-        .expect("argument in pos 1")
-        .parse()
-        .expect("numeric argument");
+    let mut words: Vec<String> = dictionaries
+        .words(&cli.lang)
+        .lines()
+        .map(str::to_lowercase)
+        .collect();
+
+    let lowered_pattern = cli.pattern.as_ref().map(|p| p.to_lowercase());
+    let word_length = cli.length.unwrap_or_else(|| {
+        lowered_pattern
+            .as_ref()
+            .expect("length or pattern")
+            .chars()
+            .count()
+    });
     let mut word = vec![None; word_length];
-    let mut missed = Vec::new();
+    if let Some(pattern) = &lowered_pattern {
+        for (i, c) in pattern.chars().enumerate() {
+            if c != BLANK_CHAR {
+                word[i] = Some(c);
+            }
+        }
+    }
+    let mut missed: Vec<char> = cli.missed.to_lowercase().chars().collect();
     let mut buf = String::new();
 
-    map.retain(|word, _| word.len() == word_length);
+    words.retain(|w| w.chars().count() == word_length);
+    let mut candidates = CandidateIndex::new(words);
+    for &letter in &missed {
+        candidates.reject_letter(letter);
+    }
+    if cli.pattern.is_some() {
+        candidates.reveal(&word);
+    }
 
     while !word.iter().all(Option::is_some) {
-        let current_words = map
-            .iter()
-            .filter(|(key, _)| {
-                key.as_str().compare(&word) && missed.iter().all(|&c| !key.contains(c))
-            })
-            .map(|(_, v)| v)
-            .collect::<Vec<_>>();
-        let mut counts = HashMap::new();
-        for count in current_words {
-            for (c, num) in count {
-                if !word.contains(&Some(*c)) {
-                    counts.entry(*c).and_modify(|e| *e += *num).or_insert(*num);
-                }
-            }
-        }
-        let mut counts: Vec<_> = counts.into_iter().collect();
-        counts.sort_by(|(_, lhs), (_, rhs)| lhs.cmp(rhs));
-        // counts.reverse();
-        println!(
-            "{:?}",
-            counts
-                .iter()
-                .map(|(c, _)| c)
-                .rev()
-                .take(3)
-                .collect::<Vec<_>>()
+        let candidate_words: Vec<&str> = candidates.words().collect();
+        let suggestions = score::rank_letters(cli.strategy, &candidate_words, &word);
+        output::report_turn(
+            cli.format,
+            &word,
+            &missed,
+            candidates.len(),
+            &suggestions,
+            cli.top,
         );
+
         buf.clear();
-        for letter in &word {
-            print!("{}", letter.unwrap_or(BLANK_CHAR));
-        }
-        println!();
-        for i in 0..word_length {
-            print!("{i}");
-        }
-        println!("\nEnter a letter, followed by positions");
         io::stdin().read_line(&mut buf).unwrap();
         let parts = buf.trim().split(' ').collect::<Vec<&str>>();
         if parts.is_empty() {
@@ -107,7 +124,7 @@ fn main() {
             continue;
         }
 
-        let letter = parts[0].to_string().chars().next().unwrap();
+        let letter = parts[0].to_lowercase().chars().next().unwrap();
         let numbers: Vec<usize> = parts
             .into_iter()
             .skip(1)
@@ -116,12 +133,14 @@ fn main() {
 
         if numbers.is_empty() {
             missed.push(letter);
+            candidates.reject_letter(letter);
             continue;
         }
 
         for position in numbers {
             word[position] = Some(letter);
         }
+        candidates.reveal(&word);
     }
 
     print!(
@@ -137,16 +156,15 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_compare_str() {
-        assert!("t__t".compare("test"));
-        assert!(!"t__t".compare("max"));
-        assert!(!"t__t".compare("naur"));
+    fn test_cli_parses_length() {
+        let cli = Cli::parse_from(["hangman", "--length", "5"]);
+        assert_eq!(cli.length, Some(5));
+        assert_eq!(cli.top, 3);
     }
 
     #[test]
-    fn test_compare_vec() {
-        assert!("test".compare(&vec![Some('t'), None, None, Some('t')]));
-        assert!(!"test".compare(&vec![Some('t'), None, Some('t')]));
-        assert!(!"test".compare(&vec![Some('s'), None, None, Some('t')]));
+    fn test_cli_parses_missed() {
+        let cli = Cli::parse_from(["hangman", "--length", "4", "--missed", "qxz"]);
+        assert_eq!(cli.missed, "qxz");
     }
 }
@@ -0,0 +1,115 @@
+use serde::Serialize;
+
+use crate::score::Suggestion;
+use crate::BLANK_CHAR;
+
+/// Which output mode to use when reporting each turn's state.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable text, as originally printed.
+    Text,
+    /// A structured JSON record per turn, for piping into jq/nushell.
+    Json,
+}
+
+#[derive(Serialize)]
+struct SuggestionRecord {
+    letter: char,
+    score: f64,
+}
+
+#[derive(Serialize)]
+struct Turn<'a> {
+    pattern: String,
+    missed: &'a [char],
+    candidates: usize,
+    suggestions: Vec<SuggestionRecord>,
+}
+
+/// Report the current state of the puzzle in the requested `format`.
+pub fn report_turn(
+    format: Format,
+    word: &[Option<char>],
+    missed: &[char],
+    candidate_count: usize,
+    suggestions: &[Suggestion],
+    top: usize,
+) {
+    match format {
+        Format::Text => report_text(word, suggestions, top),
+        Format::Json => report_json(word, missed, candidate_count, suggestions, top),
+    }
+}
+
+fn report_text(word: &[Option<char>], suggestions: &[Suggestion], top: usize) {
+    println!(
+        "{:?}",
+        suggestions
+            .iter()
+            .map(|s| s.letter)
+            .take(top)
+            .collect::<Vec<_>>()
+    );
+    for letter in word {
+        print!("{}", letter.unwrap_or(BLANK_CHAR));
+    }
+    println!();
+    for i in 0..word.len() {
+        print!("{i}");
+    }
+    println!("\nEnter a letter, followed by positions");
+}
+
+fn report_json(
+    word: &[Option<char>],
+    missed: &[char],
+    candidate_count: usize,
+    suggestions: &[Suggestion],
+    top: usize,
+) {
+    let pattern: String = word.iter().map(|c| c.unwrap_or(BLANK_CHAR)).collect();
+    let turn = Turn {
+        pattern,
+        missed,
+        candidates: candidate_count,
+        suggestions: suggestions
+            .iter()
+            .take(top)
+            .map(|s| SuggestionRecord {
+                letter: s.letter,
+                score: s.score,
+            })
+            .collect(),
+    };
+    println!("{}", serde_json::to_string(&turn).unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_turn_contains_pattern_and_suggestions() {
+        let word = [Some('t'), None, None, Some('t')];
+        let missed = ['x'];
+        let suggestions = [Suggestion {
+            letter: 'e',
+            score: 1.5,
+        }];
+        let turn = Turn {
+            pattern: word.iter().map(|c| c.unwrap_or(BLANK_CHAR)).collect(),
+            missed: &missed,
+            candidates: 4,
+            suggestions: suggestions
+                .iter()
+                .map(|s| SuggestionRecord {
+                    letter: s.letter,
+                    score: s.score,
+                })
+                .collect(),
+        };
+        let json = serde_json::to_string(&turn).unwrap();
+        assert!(json.contains("\"pattern\":\"t__t\""));
+        assert!(json.contains("\"letter\":\"e\""));
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::{HashMap, HashSet};
+
+use clap::ValueEnum;
+
+/// Which scoring strategy to use when ranking untried letters.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// Rank letters by how often they occur across all candidate words.
+    Frequency,
+    /// Rank letters by the expected information gained from guessing them.
+    Entropy,
+}
+
+/// A letter suggestion along with the score it was ranked by.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Suggestion {
+    pub letter: char,
+    pub score: f64,
+}
+
+/// Rank the as-yet-unrevealed letters across `candidates` using `strategy`,
+/// most promising first.
+pub fn rank_letters(
+    strategy: Strategy,
+    candidates: &[&str],
+    revealed: &[Option<char>],
+) -> Vec<Suggestion> {
+    match strategy {
+        Strategy::Frequency => rank_by_frequency(candidates, revealed),
+        Strategy::Entropy => rank_by_entropy(candidates, revealed),
+    }
+}
+
+fn rank_by_frequency(candidates: &[&str], revealed: &[Option<char>]) -> Vec<Suggestion> {
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for word in candidates {
+        for c in word.chars() {
+            if !revealed.contains(&Some(c)) {
+                *counts.entry(c).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let mut suggestions: Vec<Suggestion> = counts
+        .into_iter()
+        .map(|(letter, count)| Suggestion {
+            letter,
+            // Candidate counts stay far below 2^52, so this never loses precision.
+            #[allow(clippy::cast_precision_loss)]
+            score: count as f64,
+        })
+        .collect();
+    suggestions.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+    suggestions
+}
+
+/// For each untried letter, partitions `candidates` into buckets keyed by the
+/// exact set of positions the letter occupies (the empty set meaning the
+/// letter is absent), then ranks letters by the expected information gained
+/// from learning which bucket the true word falls into.
+fn rank_by_entropy(candidates: &[&str], revealed: &[Option<char>]) -> Vec<Suggestion> {
+    // Candidate counts stay far below 2^52, so this never loses precision.
+    #[allow(clippy::cast_precision_loss)]
+    let total = candidates.len() as f64;
+    if total == 0.0 {
+        return Vec::new();
+    }
+
+    let already_revealed: HashSet<char> = revealed.iter().filter_map(|&c| c).collect();
+    let letters: HashSet<char> = candidates.iter().flat_map(|word| word.chars()).collect();
+
+    let mut suggestions = Vec::new();
+    for letter in letters {
+        if already_revealed.contains(&letter) {
+            continue;
+        }
+
+        let mut buckets: HashMap<Vec<usize>, usize> = HashMap::new();
+        for word in candidates {
+            let positions: Vec<usize> = word
+                .chars()
+                .enumerate()
+                .filter_map(|(i, c)| (c == letter).then_some(i))
+                .collect();
+            *buckets.entry(positions).or_insert(0) += 1;
+        }
+
+        let entropy: f64 = buckets
+            .values()
+            .map(|&n_b| {
+                // Candidate counts stay far below 2^52, so this never loses precision.
+                #[allow(clippy::cast_precision_loss)]
+                let p = n_b as f64 / total;
+                -p * p.log2()
+            })
+            .sum();
+        suggestions.push(Suggestion {
+            letter,
+            score: entropy,
+        });
+    }
+
+    suggestions.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frequency_prefers_common_letter() {
+        // t=4 (2 in "test", 1 each in "rest"/"best"), e=3, s=3, r=1, b=1.
+        let candidates = ["test", "rest", "best"];
+        let suggestions = rank_letters(Strategy::Frequency, &candidates, &[None; 4]);
+        assert_eq!(suggestions[0].letter, 't');
+    }
+
+    #[test]
+    fn test_entropy_prefers_evenly_splitting_letter() {
+        // 'c' sits at the same spot in every word (0 bits); 'o', 'u', 'p'
+        // and 'b' each split the words 1/3 (~0.81 bits); 'a' and 't' each
+        // appear in exactly half the words at one position, a 2/2 split
+        // worth a full bit, so one of them should rank first.
+        let candidates = ["cat", "cot", "cup", "cab"];
+        let suggestions = rank_by_entropy(&candidates, &[None; 3]);
+        let top = suggestions[0].letter;
+        assert!(['a', 't'].contains(&top));
+    }
+
+    #[test]
+    fn test_entropy_excludes_revealed_letters() {
+        let candidates = ["test", "rest"];
+        let suggestions = rank_by_entropy(&candidates, &[None, Some('e'), None, None]);
+        assert!(!suggestions.iter().any(|s| s.letter == 'e'));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // the bucket sizes make this an exact 0.0, not an approximation
+    fn test_entropy_buckets_by_char_position_not_byte_offset() {
+        // 'e' sits at char position 4 in both words, even though "école"
+        // has a multi-byte first char that shifts its byte offset. Both
+        // should land in the same bucket, so 'e' carries zero information.
+        let candidates = ["azote", "école"];
+        let suggestions = rank_by_entropy(&candidates, &[None; 5]);
+        let e = suggestions.iter().find(|s| s.letter == 'e').unwrap();
+        assert_eq!(e.score, 0.0);
+    }
+}